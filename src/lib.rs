@@ -14,7 +14,7 @@
 ///     _ => otherwise
 /// }
 /// ```
-/// 
+///
 /// For example:
 /// ```
 /// sure!(val, Enum::Variant{field, name: Enum::Variant2(num)} => (field, num); return Err("oops"))
@@ -30,7 +30,17 @@
 /// sure!(v, Enum::Var1(n) => n);
 /// // Will panic with "Expected v to match pattern: Enum::Var1(n)"
 /// ```
-/// 
+///
+/// Any arm may carry an `if` guard, just like a `match`:
+/// ```
+/// sure!(val, Var1(x) if x < 20 => x; otherwise)
+/// // expands to
+/// match val {
+///     Var1(x) if x < 20 => x,
+///     _ => otherwise
+/// }
+/// ```
+///
 /// Asserting slice patterns can still feel redundant, so there are some affordances for that.
 /// If the pattern and result are the same, you can leave out the `=> result`.
 /// If a let binding, pattern, and result are all the same, you can pull in the let.
@@ -40,9 +50,46 @@
 /// let [a,b] = sure!(vec[..], [a,b]);
 /// sure!(let [a,b] = vec[..]);
 /// ```
+/// Because the inlined `let` binds the collected names, subslice patterns work too:
+/// `sure!(let [first, rest @ ..] = slice)` brings `first` and `rest` into scope.
+/// The guard threads through these forms too, so `sure!(let [a,b] = v if a != b)` works.
+///
+/// The shorthand isn't limited to tuples and slices: for a pattern that is more than a
+/// single tuple or slice group (a struct, enum or path pattern), the bindings it introduces
+/// are collected and returned as a tuple (a lone binding is returned bare, none gives `()`).
+/// A pattern that is a single tuple or slice group is still reused as the result, so it must
+/// also be a valid expression: `sure!(slice, [first, rest @ ..])` does not compile, only the
+/// inlined `let` form accepts it.
+/// ```
+/// sure!(val, Enum::Var{a, b});
+/// // expands to
+/// match val {
+///     Enum::Var{a, b} => (a, b),
+///     _ => panic!(..)
+/// }
+/// ```
+///
+/// For early-return control flow there's a `let ... else` form, where the bindings escape
+/// into the surrounding scope and the else block must diverge:
+/// ```
+/// sure!(let Some(x) = opt else { return Err("missing") });
+/// // expands to
+/// let Some(x) = opt else { return Err("missing") };
+/// ```
 ///
 #[macro_export]
 macro_rules! sure {
+    // main form, with an `if` guard
+    ($target:expr, $p:pat if $guard:expr => $res:expr; $else:expr) => {
+        match $target {
+            $p if $guard => $res,
+            _ => $else
+        }
+    };
+    ($target:expr, $p:pat if $guard:expr => $res:expr) => {
+        sure!($target, $p if $guard => $res; panic!("Expected {} to match pattern: {}", stringify!($target), stringify!($p if $guard)))
+    };
+
     // main form
     ($target:expr, $p:pat => $res:expr; $else:expr) => {
         match $target {
@@ -54,6 +101,14 @@ macro_rules! sure {
         sure!($target, $p => $res; panic!("Expected {} to match pattern: {}", stringify!($target), stringify!($p)))
     };
 
+    // self-matching form, with an `if` guard
+    ($target:expr, $pat:tt if $guard:expr; $else:expr) => {
+        sure!($target, $pat if $guard => $pat; $else)
+    };
+    ($target:expr, $pat:tt if $guard:expr) => {
+        sure!($target, $pat if $guard => $pat)
+    };
+
     // self-matching form
     // for tuple or slice patterns that also form the proper bindings
     ($target:expr, $pat:tt; $else:expr) => {
@@ -63,11 +118,287 @@ macro_rules! sure {
         sure!($target, $pat => $pat)
     };
 
+    // self-matching form for any pattern
+    // a struct/enum pattern isn't a valid expression, so instead of reusing the
+    // pattern tokens we collect the names it binds and return them as a tuple.
+    ($target:expr, $($p:tt)+) => {
+        match $target {
+            $($p)+ => sure!(@bindings [] $($p)+),
+            _ => panic!("Expected {} to match pattern: {}", stringify!($target), stringify!($($p)+))
+        }
+    };
+
     // inlined let, for self-matching bindings
+    // bind the collected names rather than the pattern itself, so rest patterns like
+    // `[first, rest @ ..]` — which can't be spelled as an expression — still work.
     (let $pat:tt = $target:expr; $else:expr) => {
-        let $pat = sure!($target, $pat; $else);
+        sure!(@let_bind [[$target] [$pat] [$else]] [] $pat)
     };
     (let $pat:tt = $target:expr) => {
-        let $pat = sure!($target, $pat);
+        sure!(@let_bind
+            [[$target] [$pat]
+             [panic!("Expected {} to match pattern: {}", stringify!($target), stringify!($pat))]]
+            [] $pat)
+    };
+
+    // inlined let with an `if` guard
+    // `if` can't follow an `:expr` matcher, so peel the target tokens off by hand.
+    (let $pat:tt = $($rest:tt)+) => {
+        sure!(@let_guard [$pat] [] $($rest)+)
+    };
+    (@let_guard [$pat:tt] [$($t:tt)*] if $guard:expr; $else:expr) => {
+        let $pat = sure!({ $($t)* }, $pat if $guard; $else);
+    };
+    (@let_guard [$pat:tt] [$($t:tt)*] if $guard:expr) => {
+        let $pat = sure!({ $($t)* }, $pat if $guard);
+    };
+    (@let_guard [$pat:tt] [$($t:tt)*] else $block:block) => {
+        let $pat = ({ $($t)* }) else $block;
+    };
+    (@let_guard [$pat:tt] [$($t:tt)*] $next:tt $($rest:tt)*) => {
+        sure!(@let_guard [$pat] [$($t)* $next] $($rest)*)
+    };
+
+    // first-class let-else: the bindings escape into the surrounding scope and the
+    // else block is required to diverge, exactly like the `let ... else` RFC.
+    // `:expr` can't be followed by `else`, so peel the target tokens off by hand.
+    (let $pat:pat = $($rest:tt)+) => {
+        sure!(@let_else [$pat] [] $($rest)+)
+    };
+    (@let_else [$pat:pat] [$($t:tt)*] else $block:block) => {
+        let $pat = ({ $($t)* }) else $block;
+    };
+    (@let_else [$pat:pat] [$($t:tt)*] $next:tt $($rest:tt)*) => {
+        sure!(@let_else [$pat] [$($t)* $next] $($rest)*)
+    };
+    // A non-`tt` pattern with no `else` can't use the `tt` panic-default shorthand and
+    // isn't a let-else, so say so clearly instead of dying deep inside the macro.
+    (@let_else [$pat:pat] [$($t:tt)*]) => {
+        compile_error!("sure!(let PATTERN = EXPR) needs an `else { .. }` block for this pattern; use a tuple/slice pattern for the panic default")
+    };
+
+    // Back the inlined `let` with the binding collector: walk the pattern (same rules as
+    // `@bindings`, carrying the target/pattern/else context) and let-bind the collected
+    // names as a tuple so the bindings land in the surrounding scope.
+    (@let_bind [[$t:expr] [$p:tt] [$e:expr]] []) => {
+        let () = match $t { $p => (), _ => $e };
+    };
+    (@let_bind [[$t:expr] [$p:tt] [$e:expr]] [$single:ident]) => {
+        let $single = match $t { $p => $single, _ => $e };
+    };
+    (@let_bind [[$t:expr] [$p:tt] [$e:expr]] [$first:ident $($more:ident)+]) => {
+        let ($first, $($more),+) = match $t { $p => ($first, $($more),+), _ => $e };
+    };
+    // a path segment `Path::Segment` — drop both idents so a trailing unit variant or
+    // path constant is never mistaken for a binding.
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident :: $seg:ident $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    // continuation of a multi-segment path (`:: Segment`) — drop the segment too.
+    (@let_bind $ctx:tt [$($acc:ident)*] :: $seg:ident $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    // turbofish generics `Path::<..>` — drop the leading ident and keep walking.
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident :: $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    // struct field `field: subpat` — drop the field name and walk the subpattern.
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident : $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident @ $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)* $id] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident ( $($inner:tt)* ) $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident { $($inner:tt)* } $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident ! $group:tt $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] _ $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] ref $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] mut $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] & $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] .. $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] ( $($inner:tt)* ) $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] [ $($inner:tt)* ] $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] { $($inner:tt)* } $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] $lit:literal $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] $id:ident $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)* $id] $($rest)*)
+    };
+    (@let_bind $ctx:tt [$($acc:ident)*] $other:tt $($rest:tt)*) => {
+        sure!(@let_bind $ctx [$($acc)*] $($rest)*)
+    };
+
+    // Walk a pattern's token tree and collect the identifiers it binds, emitting them
+    // as a tuple (or a bare ident when there's exactly one, or `()` when there are none).
+    // A binding is an ident that isn't immediately followed by `::`, `(`, `{`, or `!`
+    // (so not a path, variant, struct, or macro), isn't `_`/`ref`/`mut`/a literal, and
+    // `name @ subpat` binds `name`.
+    (@bindings [$($acc:ident)*]) => {
+        sure!(@emit $($acc)*)
+    };
+    // a path segment `Path::Segment` — drop both idents so a trailing unit variant or
+    // path constant (`E::Unit`, `Ordering::Less`) is never mistaken for a binding.
+    (@bindings [$($acc:ident)*] $id:ident :: $seg:ident $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    // continuation of a multi-segment path (`:: Segment`) — drop the segment too.
+    (@bindings [$($acc:ident)*] :: $seg:ident $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    // turbofish generics `Path::<..>` — drop the leading ident and keep walking.
+    (@bindings [$($acc:ident)*] $id:ident :: $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    // a trailing `if` guard isn't part of the pattern — stop collecting here.
+    (@bindings [$($acc:ident)*] if $($rest:tt)*) => {
+        sure!(@emit $($acc)*)
+    };
+    // struct field `field: subpat` — drop the field name and walk the subpattern.
+    (@bindings [$($acc:ident)*] $id:ident : $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] $id:ident @ $($rest:tt)*) => {
+        sure!(@bindings [$($acc)* $id] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] $id:ident ( $($inner:tt)* ) $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] $id:ident { $($inner:tt)* } $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] $id:ident ! $group:tt $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] _ $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] ref $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] mut $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] & $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] .. $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] ( $($inner:tt)* ) $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] [ $($inner:tt)* ] $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] { $($inner:tt)* } $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($inner)* , $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] $lit:literal $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] $id:ident $($rest:tt)*) => {
+        sure!(@bindings [$($acc)* $id] $($rest)*)
+    };
+    (@bindings [$($acc:ident)*] $other:tt $($rest:tt)*) => {
+        sure!(@bindings [$($acc)*] $($rest)*)
+    };
+
+    (@emit) => { () };
+    (@emit $single:ident) => { $single };
+    (@emit $first:ident $($rest:ident)+) => { ($first, $($rest),+) };
+}
+
+/// Like [`sure!`], but non-panicking: evaluates to `Some(result)` on a match and `None` otherwise.
+///
+/// ```
+/// match_ok!(val, Enum::Var1(n) => n)
+/// // expands to
+/// match val {
+///     Enum::Var1(n) => Some(n),
+///     _ => None
+/// }
+/// ```
+/// It shares [`sure!`]'s arm shapes: the self-matching `$pat:tt` shorthand, an optional
+/// `=> result`, and an optional `if` guard. This covers the common
+/// `if let ... { Some(x) } else { None }` boilerplate directly.
+#[macro_export]
+macro_rules! match_ok {
+    ($target:expr, $p:pat if $guard:expr => $res:expr) => {
+        match $target {
+            $p if $guard => Some($res),
+            _ => None
+        }
+    };
+    ($target:expr, $p:pat => $res:expr) => {
+        match $target {
+            $p => Some($res),
+            _ => None
+        }
+    };
+    ($target:expr, $pat:tt if $guard:expr) => {
+        match_ok!($target, $pat if $guard => $pat)
+    };
+    ($target:expr, $pat:tt) => {
+        match_ok!($target, $pat => $pat)
+    };
+}
+
+/// Like [`sure!`], but non-panicking: evaluates to `Ok(result)` on a match and `Err(val)`
+/// (the original input) otherwise.
+///
+/// ```
+/// try_sure!(val, Enum::Var1(n) => n)
+/// // expands to
+/// match val {
+///     Enum::Var1(n) => Ok(n),
+///     other => Err(other)
+/// }
+/// ```
+/// Like [`match_ok!`] it shares [`sure!`]'s arm shapes: the self-matching `$pat:tt` shorthand,
+/// an optional `=> result`, and an optional `if` guard. Handing the input back in the `Err`
+/// lets callers recover or retry without constructing a sentinel `$else`.
+#[macro_export]
+macro_rules! try_sure {
+    ($target:expr, $p:pat if $guard:expr => $res:expr) => {
+        match $target {
+            $p if $guard => Ok($res),
+            other => Err(other)
+        }
+    };
+    ($target:expr, $p:pat => $res:expr) => {
+        match $target {
+            $p => Ok($res),
+            other => Err(other)
+        }
+    };
+    ($target:expr, $pat:tt if $guard:expr) => {
+        try_sure!($target, $pat if $guard => $pat)
+    };
+    ($target:expr, $pat:tt) => {
+        try_sure!($target, $pat => $pat)
     };
 }